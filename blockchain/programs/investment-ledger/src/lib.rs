@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("FEQJZDk4afcXbSrRj7iW3PieNtrmeT2Hjtt5BCmoNfRr");
 
@@ -6,10 +7,67 @@ declare_id!("FEQJZDk4afcXbSrRj7iW3PieNtrmeT2Hjtt5BCmoNfRr");
 use anchor_lang::solana_program::pubkey;
 const STARTUP_REGISTRY_PROGRAM_ID: Pubkey = pubkey!("DqwhC5DDZZmL4E1f4YYQJ9R121NurZV8ttk2dfGoYnTj");
 
+// Configured USDC mint this program accepts investments in
+const USDC_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+
+/// Loads and validates the `Startup` mirrored from the Startup Registry
+/// Program. `seeds::program` on the account constraint only pins the PDA's
+/// *address* derivation to that program - it does not check who owns the
+/// account, and `Account<'info, Startup>` can't check that for us either
+/// since `Startup`'s derived `Owner` impl resolves to *this* crate's
+/// `declare_id!`. So `startup` is taken as a plain `AccountInfo` and we
+/// check ownership and deserialize (discriminator included) by hand.
+fn load_startup(startup_info: &AccountInfo) -> Result<Startup> {
+    require!(
+        startup_info.owner == &STARTUP_REGISTRY_PROGRAM_ID,
+        ErrorCode::InvalidStartup
+    );
+    Startup::try_deserialize(&mut &startup_info.data.borrow()[..])
+        .map_err(|_| error!(ErrorCode::InvalidStartup))
+}
+
 #[program]
 pub mod investment_ledger {
     use super::*;
 
+    /// Opens a funding round for a startup with soft/hard caps.
+    /// Callable only by the startup's founder.
+    #[access_control(only_founder_for_init_round(&ctx))]
+    pub fn init_funding_round(
+        ctx: Context<InitFundingRound>,
+        startup_id: String,
+        soft_cap: u64,
+        hard_cap: u64,
+    ) -> Result<()> {
+        require!(soft_cap <= hard_cap, ErrorCode::CapExceeded);
+
+        let round = &mut ctx.accounts.funding_round;
+        round.startup_id = startup_id;
+        round.soft_cap = soft_cap;
+        round.hard_cap = hard_cap;
+        round.total_raised_usdc = 0;
+        round.investor_count = 0;
+        round.open = true;
+
+        msg!("Funding round opened for {}", round.startup_id);
+        Ok(())
+    }
+
+    /// Closes a funding round once the soft cap has been reached.
+    /// Callable only by the startup's founder.
+    #[access_control(only_founder_for_close_round(&ctx))]
+    pub fn close_round(ctx: Context<CloseRound>, _startup_id: String) -> Result<()> {
+        let round = &mut ctx.accounts.funding_round;
+        require!(
+            round.total_raised_usdc >= round.soft_cap,
+            ErrorCode::CapExceeded
+        );
+        round.open = false;
+
+        msg!("Funding round closed for {}", round.startup_id);
+        Ok(())
+    }
+
     /// Records a new investment transaction
     /// Verifies startup exists on-chain before recording investment
     pub fn record_investment(
@@ -18,24 +76,47 @@ pub mod investment_ledger {
         startup_id: String,
         amount_usdc: u64,
     ) -> Result<()> {
-        // On-chain verification: Verify startup exists
-        // Anchor's account constraint above already verifies:
-        // 1. Startup account exists (via PDA seeds)
-        // 2. Account is owned by Startup Registry Program (via seeds::program)
+        // The seeds constraint on `startup` only pins down its PDA address;
+        // `load_startup` does the rest of the on-chain verification (owner
+        // is the Startup Registry Program, data matches the `Startup`
+        // discriminator).
+        let startup = load_startup(&ctx.accounts.startup)?;
+        require!(startup.startup_id == startup_id, ErrorCode::InvalidStartup);
+
+        msg!("Startup verified on-chain: {}", startup_id);
 
-        // Verify account is owned by Startup Registry
+        require!(amount_usdc > 0, ErrorCode::InvalidAmount);
         require!(
-            ctx.accounts.startup.owner == &STARTUP_REGISTRY_PROGRAM_ID,
-            ErrorCode::InvalidStartup
+            ctx.accounts.mint.key() == USDC_MINT,
+            ErrorCode::InvalidAmount
         );
 
-        // Verify account has data (more than just discriminator)
+        let round = &ctx.accounts.funding_round;
         require!(
-            ctx.accounts.startup.data_len() > 8,
-            ErrorCode::InvalidStartup
+            round.open
+                && round
+                    .total_raised_usdc
+                    .checked_add(amount_usdc)
+                    .ok_or(ErrorCode::CapExceeded)?
+                    <= round.hard_cap,
+            ErrorCode::CapExceeded
         );
 
-        msg!("Startup verified on-chain: {}", startup_id);
+        // Escrow the investor's USDC in the startup's vault before the
+        // ledger entry is persisted, so a failed transfer never leaves a
+        // bookkeeping-only record behind. Funds only leave the vault once
+        // the founder accepts (or the investor cancels before acceptance).
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.investor_token_account.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.investor.to_account_info(),
+                },
+            ),
+            amount_usdc,
+        )?;
 
         let investment = &mut ctx.accounts.investment;
         let clock = Clock::get()?;
@@ -45,16 +126,101 @@ pub mod investment_ledger {
         investment.startup_id = startup_id;
         investment.amount_usdc = amount_usdc;
         investment.timestamp = clock.unix_timestamp;
-        investment.status = "confirmed".to_string();
+        investment.status = "pending".to_string();
+
+        let round = &mut ctx.accounts.funding_round;
+        round.total_raised_usdc += amount_usdc;
+        round.investor_count += 1;
 
         msg!(
-            "Investment recorded: {} USDC to {}",
+            "Investment escrowed: {} USDC pending for {}",
             amount_usdc,
             investment.startup_id
         );
         Ok(())
     }
 
+    /// Releases escrowed funds to the startup once the founder accepts the
+    /// investment. Callable only by the startup's founder.
+    #[access_control(only_founder(&ctx))]
+    pub fn accept_investment(
+        ctx: Context<AcceptInvestment>,
+        _investment_id: String,
+        startup_id: String,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.investment.status == "pending",
+            ErrorCode::InvalidInvestment
+        );
+        require!(
+            ctx.accounts.investment.startup_id == startup_id,
+            ErrorCode::InvalidStartup
+        );
+
+        let bump = ctx.bumps.vault;
+        let seeds: &[&[u8]] = &[b"vault", startup_id.as_bytes(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.startup_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[seeds],
+            ),
+            ctx.accounts.investment.amount_usdc,
+        )?;
+
+        ctx.accounts.investment.status = "confirmed".to_string();
+        msg!("Investment accepted: {}", ctx.accounts.investment.investment_id);
+        Ok(())
+    }
+
+    /// Refunds escrowed funds to the investor before the founder accepts.
+    /// Callable only by the original investor.
+    #[access_control(only_investor(&ctx))]
+    pub fn cancel_investment(
+        ctx: Context<CancelInvestment>,
+        _investment_id: String,
+        startup_id: String,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.investment.status == "pending",
+            ErrorCode::InvalidInvestment
+        );
+        require!(
+            ctx.accounts.investment.startup_id == startup_id,
+            ErrorCode::InvalidStartup
+        );
+
+        let bump = ctx.bumps.vault;
+        let seeds: &[&[u8]] = &[b"vault", startup_id.as_bytes(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.investor_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[seeds],
+            ),
+            ctx.accounts.investment.amount_usdc,
+        )?;
+
+        let amount_usdc = ctx.accounts.investment.amount_usdc;
+        ctx.accounts.investment.status = "cancelled".to_string();
+
+        // Refunded escrow no longer counts toward the round's raised total
+        let round = &mut ctx.accounts.funding_round;
+        round.total_raised_usdc = round.total_raised_usdc.saturating_sub(amount_usdc);
+        round.investor_count = round.investor_count.saturating_sub(1);
+
+        msg!("Investment cancelled: {}", ctx.accounts.investment.investment_id);
+        Ok(())
+    }
+
     /// Gets investment history for an investor
     pub fn get_investment_history(ctx: Context<GetInvestmentHistory>) -> Result<()> {
         let investment = &ctx.accounts.investment;
@@ -75,6 +241,73 @@ pub mod investment_ledger {
     }
 }
 
+/// Shared founder check behind every `only_founder*` access-control fn
+/// below: loads the startup from `startup_info` and requires `signer` to
+/// match the founder recorded on it. Pulled out once since `access_control`
+/// needs a distinct thin wrapper per `Context<T>`, but the check itself is
+/// identical across all three.
+fn assert_is_founder(startup_info: &AccountInfo, signer: Pubkey) -> Result<()> {
+    let startup = load_startup(startup_info)?;
+    require_keys_eq!(signer, startup.founder, ErrorCode::Unauthorized);
+    Ok(())
+}
+
+fn only_founder_for_init_round(ctx: &Context<InitFundingRound>) -> Result<()> {
+    assert_is_founder(&ctx.accounts.startup, ctx.accounts.founder.key())
+}
+
+fn only_founder_for_close_round(ctx: &Context<CloseRound>) -> Result<()> {
+    assert_is_founder(&ctx.accounts.startup, ctx.accounts.founder.key())
+}
+
+fn only_founder(ctx: &Context<AcceptInvestment>) -> Result<()> {
+    assert_is_founder(&ctx.accounts.startup, ctx.accounts.founder.key())
+}
+
+/// `cancel_investment` protects the investor's own escrowed funds rather
+/// than anything founder-owned, so it checks against `investment`
+/// directly instead of going through `assert_is_founder`.
+fn only_investor(ctx: &Context<CancelInvestment>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.investor.key(),
+        ctx.accounts.investment.investor_address,
+        ErrorCode::Unauthorized
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(startup_id: String)]
+pub struct InitFundingRound<'info> {
+    #[account(
+        init,
+        payer = founder,
+        space = 8 + 4 + 100 + 8 + 8 + 8 + 8 + 1,
+        seeds = [b"round", startup_id.as_bytes()],
+        bump
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+    /// CHECK: verified in `load_startup` (owner is the Startup Registry
+    /// Program, data matches the `Startup` discriminator)
+    #[account(seeds = [b"startup", startup_id.as_bytes()], bump, seeds::program = STARTUP_REGISTRY_PROGRAM_ID)]
+    pub startup: AccountInfo<'info>,
+    #[account(mut)]
+    pub founder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(startup_id: String)]
+pub struct CloseRound<'info> {
+    #[account(mut, seeds = [b"round", startup_id.as_bytes()], bump)]
+    pub funding_round: Account<'info, FundingRound>,
+    /// CHECK: verified in `load_startup` (owner is the Startup Registry
+    /// Program, data matches the `Startup` discriminator)
+    #[account(seeds = [b"startup", startup_id.as_bytes()], bump, seeds::program = STARTUP_REGISTRY_PROGRAM_ID)]
+    pub startup: AccountInfo<'info>,
+    pub founder: Signer<'info>,
+}
+
 #[derive(Accounts)]
 #[instruction(investment_id: String, startup_id: String)]
 pub struct RecordInvestment<'info> {
@@ -88,30 +321,100 @@ pub struct RecordInvestment<'info> {
     pub investment: Account<'info, Investment>,
     #[account(mut)]
     pub investor: Signer<'info>,
-    /// Startup account from Startup Registry - verified on-chain
-    /// PDA: [b"startup", startup_id.as_bytes()]
-    /// Must be owned by Startup Registry Program
-    /// CHECK: We verify ownership in the instruction logic
+    /// Startup account from Startup Registry, PDA: [b"startup", startup_id.as_bytes()]
+    /// CHECK: verified in `load_startup` (owner is the Startup Registry
+    /// Program, data matches the `Startup` discriminator)
     #[account(
         seeds = [b"startup", startup_id.as_bytes()],
         bump,
         seeds::program = STARTUP_REGISTRY_PROGRAM_ID
     )]
     pub startup: AccountInfo<'info>,
+    /// USDC mint configured for this program; must match `USDC_MINT`
+    pub mint: Account<'info, Mint>,
+    /// Investor's USDC token account, debited for `amount_usdc`
+    #[account(mut, token::mint = mint, token::authority = investor)]
+    pub investor_token_account: Account<'info, TokenAccount>,
+    /// Per-startup vault holding escrowed USDC; the vault PDA is its own authority
+    #[account(
+        init_if_needed,
+        payer = investor,
+        seeds = [b"vault", startup_id.as_bytes()],
+        bump,
+        token::mint = mint,
+        token::authority = vault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// Aggregate funding totals and caps for this startup's round
+    #[account(mut, seeds = [b"round", startup_id.as_bytes()], bump)]
+    pub funding_round: Account<'info, FundingRound>,
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-// Startup account structure (matches Startup Registry)
+#[derive(Accounts)]
+#[instruction(investment_id: String, startup_id: String)]
+pub struct AcceptInvestment<'info> {
+    #[account(mut, seeds = [b"investment", investment_id.as_bytes()], bump)]
+    pub investment: Account<'info, Investment>,
+    /// CHECK: verified in `load_startup` (owner is the Startup Registry
+    /// Program, data matches the `Startup` discriminator)
+    #[account(seeds = [b"startup", startup_id.as_bytes()], bump, seeds::program = STARTUP_REGISTRY_PROGRAM_ID)]
+    pub startup: AccountInfo<'info>,
+    pub founder: Signer<'info>,
+    #[account(mut, seeds = [b"vault", startup_id.as_bytes()], bump, token::mint = mint, token::authority = vault)]
+    pub vault: Account<'info, TokenAccount>,
+    /// Startup's receiving token account; funds are released here on acceptance
+    #[account(mut, token::mint = mint)]
+    pub startup_token_account: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(investment_id: String, startup_id: String)]
+pub struct CancelInvestment<'info> {
+    #[account(mut, seeds = [b"investment", investment_id.as_bytes()], bump)]
+    pub investment: Account<'info, Investment>,
+    /// CHECK: bound to the investment's startup by its own seeds; not read
+    #[account(seeds = [b"startup", startup_id.as_bytes()], bump, seeds::program = STARTUP_REGISTRY_PROGRAM_ID)]
+    pub startup: AccountInfo<'info>,
+    pub investor: Signer<'info>,
+    #[account(mut, seeds = [b"vault", startup_id.as_bytes()], bump, token::mint = mint, token::authority = vault)]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint, token::authority = investor)]
+    pub investor_token_account: Account<'info, TokenAccount>,
+    /// Aggregate funding totals and caps for this startup's round
+    #[account(mut, seeds = [b"round", startup_id.as_bytes()], bump)]
+    pub funding_round: Account<'info, FundingRound>,
+    pub mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Mirrors the Startup Registry's account layout so `load_startup` can
+// deserialize it here; the struct name must stay `Startup` so its
+// discriminator (sha256("account:Startup")) matches what the registry
+// program writes on-chain
 #[account]
-pub struct StartupAccount {
+pub struct Startup {
     pub startup_id: String,
     pub name: String,
     pub sector: String,
-    pub founder_address: Pubkey,
+    pub founder: Pubkey,
     pub employee_addresses: Vec<String>,
     pub registration_timestamp: i64,
 }
 
+#[account]
+pub struct FundingRound {
+    pub startup_id: String,
+    pub soft_cap: u64,
+    pub hard_cap: u64,
+    pub total_raised_usdc: u64,
+    pub investor_count: u64,
+    pub open: bool,
+}
+
 #[derive(Accounts)]
 pub struct GetInvestmentHistory<'info> {
     pub investment: Account<'info, Investment>,
@@ -140,4 +443,8 @@ pub enum ErrorCode {
     InvalidAmount,
     #[msg("Invalid startup - startup not found or invalid")]
     InvalidStartup,
+    #[msg("Unauthorized - signer is not permitted to perform this action")]
+    Unauthorized,
+    #[msg("Funding round cap check failed: deposit over the hard cap or round closed, soft cap above hard cap, or soft cap not yet reached")]
+    CapExceeded,
 }
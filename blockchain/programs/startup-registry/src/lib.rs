@@ -7,6 +7,24 @@ use anchor_lang::solana_program::pubkey;
 const CERTIFICATE_REGISTRY_PROGRAM_ID: Pubkey =
     pubkey!("D7SYneSxju3iTtJW9HPQMVjQRXgTCZi2vR2UWRk8nTRa");
 
+// Fixed-size portion of `Startup` (discriminator + bounded strings + founder + vec len prefix + timestamp)
+const STARTUP_BASE_SPACE: usize = 8 + 4 + 100 + 4 + 100 + 4 + 100 + 32 + 4 + 8;
+// Space reserved per employee certificate id (4-byte length prefix + 100 char cap); `register_startup` reserves
+// no slots and `add_employee` grows the account by one slot per new employee
+const EMPLOYEE_SLOT_SPACE: usize = 4 + 100;
+
+/// Roster space `startup` needs to hold `certificate_id` as a new employee;
+/// stays at the current size for an already-present certificate so a
+/// duplicate `add_employee` call doesn't pay for a pointless reallocation
+fn next_startup_space(startup: &Account<Startup>, certificate_id: &str) -> usize {
+    let slots = if startup.employee_addresses.iter().any(|e| e == certificate_id) {
+        startup.employee_addresses.len()
+    } else {
+        startup.employee_addresses.len() + 1
+    };
+    STARTUP_BASE_SPACE + EMPLOYEE_SLOT_SPACE * slots
+}
+
 #[program]
 pub mod startup_registry {
     use super::*;
@@ -24,7 +42,7 @@ pub mod startup_registry {
         startup.startup_id = startup_id;
         startup.name = name;
         startup.sector = sector;
-        startup.founder_address = ctx.accounts.founder.key();
+        startup.founder = ctx.accounts.founder.key();
         startup.employee_addresses = Vec::new();
         startup.registration_timestamp = clock.unix_timestamp;
 
@@ -34,6 +52,8 @@ pub mod startup_registry {
 
     /// Adds an employee with verified certificate to startup
     /// Verifies certificate exists on-chain before adding
+    /// Only the startup's founder may call this
+    #[access_control(only_founder(&ctx))]
     pub fn add_employee(ctx: Context<AddEmployee>, certificate_id: String) -> Result<()> {
         let startup = &mut ctx.accounts.startup;
 
@@ -63,7 +83,8 @@ pub mod startup_registry {
             certificate.student_name
         );
 
-        // Check if employee already exists
+        // Check if employee already exists. The `realloc` constraint above
+        // has already grown the account (if needed) to fit this certificate.
         if !startup.employee_addresses.contains(&certificate_id) {
             let cert_id_clone = certificate_id.clone();
             startup.employee_addresses.push(certificate_id);
@@ -95,7 +116,7 @@ pub struct RegisterStartup<'info> {
     #[account(
         init,
         payer = founder,
-        space = 8 + 4 + 100 + 4 + 100 + 4 + 100 + 32 + 4 + (4 + 100) * 10 + 8,
+        space = STARTUP_BASE_SPACE,
         seeds = [b"startup", startup_id.as_bytes()],
         bump
     )]
@@ -105,12 +126,28 @@ pub struct RegisterStartup<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Only the founder on record may grow their own startup's employee roster
+fn only_founder(ctx: &Context<AddEmployee>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.founder.key(),
+        ctx.accounts.startup.founder,
+        ErrorCode::Unauthorized
+    );
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(certificate_id: String)]
 pub struct AddEmployee<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = founder,
+        realloc = next_startup_space(&startup, &certificate_id),
+        realloc::payer = founder,
+        realloc::zero = false
+    )]
     pub startup: Account<'info, Startup>,
-    pub employee: Signer<'info>,
+    pub founder: Signer<'info>,
     /// Certificate account from Certificate Registry - verified on-chain
     /// PDA: [b"certificate", certificate_id.as_bytes()]
     /// Must be owned by Certificate Registry Program
@@ -120,6 +157,7 @@ pub struct AddEmployee<'info> {
         seeds::program = CERTIFICATE_REGISTRY_PROGRAM_ID
     )]
     pub certificate: Account<'info, CertificateAccount>,
+    pub system_program: Program<'info, System>,
 }
 
 // Import Certificate struct from certificate-registry
@@ -145,7 +183,7 @@ pub struct Startup {
     pub startup_id: String,
     pub name: String,
     pub sector: String,
-    pub founder_address: Pubkey,
+    pub founder: Pubkey,
     pub employee_addresses: Vec<String>,
     pub registration_timestamp: i64,
 }
@@ -158,4 +196,8 @@ pub enum ErrorCode {
     EmployeeExists,
     #[msg("Invalid certificate - certificate not found or invalid")]
     InvalidCertificate,
+    #[msg("Only the startup's founder may perform this action")]
+    Unauthorized,
+    #[msg("Failed to reallocate the startup account for the growing employee roster")]
+    ReallocFailed,
 }